@@ -0,0 +1,247 @@
+//! On-disk cache for repository listings
+//!
+//! Listing an org's repositories hits the network every time, which burns
+//! into GitHub's rate limit and fails outright when offline. [`RepoCache`]
+//! persists the last listing for an org as JSON, keyed by org name, with a
+//! TTL controlling how long a cached copy is served before a refetch.
+
+use crate::error::{Error, Result};
+use crate::types::Repository;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// A cached repository listing and when it was fetched
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    fetched_at: DateTime<Utc>,
+    repositories: Vec<Repository>,
+}
+
+/// On-disk, JSON-backed cache of per-org repository listings
+pub struct RepoCache {
+    dir: PathBuf,
+}
+
+impl RepoCache {
+    /// Create a cache rooted at `dir`; the directory is created on the first [`RepoCache::put`]
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Default cache location, under the platform's cache directory
+    pub fn default_dir() -> Result<PathBuf> {
+        let cache_dir = dirs::cache_dir()
+            .ok_or_else(|| Error::Config("Could not determine cache directory".to_string()))?;
+        Ok(cache_dir.join("raibid-cli"))
+    }
+
+    /// Read a cached listing for `org`, ignoring its age
+    pub fn get_stale(&self, org: &str) -> Option<Vec<Repository>> {
+        let content = std::fs::read_to_string(self.path_for(org)).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&content).ok()?;
+        Some(entry.repositories)
+    }
+
+    /// Read a cached listing for `org` if it was fetched within `ttl`
+    pub fn get(&self, org: &str, ttl: Duration) -> Option<Vec<Repository>> {
+        let content = std::fs::read_to_string(self.path_for(org)).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&content).ok()?;
+        let age = Utc::now().signed_duration_since(entry.fetched_at);
+        if age.to_std().ok()? <= ttl {
+            Some(entry.repositories)
+        } else {
+            None
+        }
+    }
+
+    /// Persist a freshly-fetched listing for `org`
+    pub fn put(&self, org: &str, repositories: &[Repository]) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let entry = CacheEntry {
+            fetched_at: Utc::now(),
+            repositories: repositories.to_vec(),
+        };
+        let content = serde_json::to_string_pretty(&entry)?;
+        std::fs::write(self.path_for(org), content)?;
+        Ok(())
+    }
+
+    fn path_for(&self, org: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", sanitize(org)))
+    }
+}
+
+/// Replace path separators so an org name can't escape the cache directory
+fn sanitize(org: &str) -> String {
+    org.replace(['/', '\\'], "_")
+}
+
+/// How a cached listing should be consulted before hitting the network
+#[derive(Debug, Clone, Copy)]
+pub struct CachePolicy {
+    /// How long a cached listing stays fresh
+    pub ttl: Duration,
+
+    /// Skip the cache and force a network fetch, still refreshing the cache
+    pub refresh: bool,
+
+    /// Never touch the network; serve the cache regardless of age
+    pub offline: bool,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(15 * 60),
+            refresh: false,
+            offline: false,
+        }
+    }
+}
+
+/// Fetch `org`'s repositories via `fetch`, honoring `policy`'s cache rules
+///
+/// `fetch` is only invoked on a cache miss (or `policy.refresh`); its result
+/// is written back to `cache` so the next call can be served from disk.
+pub async fn list_repositories_cached<F, Fut>(
+    cache: &RepoCache,
+    org: &str,
+    policy: CachePolicy,
+    fetch: F,
+) -> Result<Vec<Repository>>
+where
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = Result<Vec<Repository>>>,
+{
+    if policy.offline {
+        return cache
+            .get_stale(org)
+            .ok_or_else(|| Error::Config(format!("offline and no cache for org `{}`", org)));
+    }
+
+    if !policy.refresh {
+        if let Some(repos) = cache.get(org, policy.ttl) {
+            return Ok(repos);
+        }
+    }
+
+    let repos = fetch().await?;
+    cache.put(org, &repos)?;
+    Ok(repos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn test_repo(name: &str) -> Repository {
+        Repository {
+            name: name.to_string(),
+            full_name: format!("org/{}", name),
+            description: None,
+            clone_url: format!("https://github.com/org/{}", name),
+            ssh_url: format!("git@github.com:org/{}.git", name),
+            default_branch: "main".to_string(),
+            private: false,
+            fork: false,
+            archived: false,
+            language: None,
+            stargazers_count: 0,
+            forks_count: 0,
+            updated_at: Utc::now(),
+            pushed_at: Utc::now(),
+            topics: vec![],
+            forge: "github".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_put_then_get_within_ttl() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = RepoCache::new(dir.path().to_path_buf());
+        cache.put("raibid-labs", &[test_repo("a")]).unwrap();
+
+        let repos = cache.get("raibid-labs", Duration::from_secs(60)).unwrap();
+        assert_eq!(repos.len(), 1);
+        assert_eq!(repos[0].name, "a");
+    }
+
+    #[test]
+    fn test_get_misses_when_expired() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = RepoCache::new(dir.path().to_path_buf());
+        cache.put("raibid-labs", &[test_repo("a")]).unwrap();
+
+        assert!(cache.get("raibid-labs", Duration::from_secs(0)).is_none());
+    }
+
+    #[test]
+    fn test_get_misses_for_unknown_org() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = RepoCache::new(dir.path().to_path_buf());
+        assert!(cache.get("unknown", Duration::from_secs(60)).is_none());
+    }
+
+    #[test]
+    fn test_sanitize_blocks_path_traversal() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = RepoCache::new(dir.path().to_path_buf());
+        assert_eq!(cache.path_for("../../etc"), dir.path().join(".._.._etc.json"));
+    }
+
+    #[tokio::test]
+    async fn test_list_repositories_cached_hits_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = RepoCache::new(dir.path().to_path_buf());
+        cache.put("raibid-labs", &[test_repo("a")]).unwrap();
+
+        let policy = CachePolicy::default();
+        let repos = list_repositories_cached(&cache, "raibid-labs", policy, || async {
+            panic!("fetch should not be called on a cache hit")
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(repos.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_repositories_cached_refresh_bypasses_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = RepoCache::new(dir.path().to_path_buf());
+        cache.put("raibid-labs", &[test_repo("a")]).unwrap();
+
+        let policy = CachePolicy {
+            refresh: true,
+            ..Default::default()
+        };
+        let repos = list_repositories_cached(&cache, "raibid-labs", policy, || async {
+            Ok(vec![test_repo("b")])
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(repos[0].name, "b");
+        assert_eq!(cache.get("raibid-labs", Duration::from_secs(60)).unwrap()[0].name, "b");
+    }
+
+    #[tokio::test]
+    async fn test_list_repositories_cached_offline_without_cache_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = RepoCache::new(dir.path().to_path_buf());
+
+        let policy = CachePolicy {
+            offline: true,
+            ..Default::default()
+        };
+        let result = list_repositories_cached(&cache, "raibid-labs", policy, || async {
+            panic!("fetch should not be called while offline")
+        })
+        .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -23,8 +23,8 @@ pub struct Config {
     /// Git settings
     pub git: GitConfig,
 
-    /// GitHub settings
-    pub github: GitHubConfig,
+    /// Configured forge backends (GitHub, Forgejo, Gitea, ...)
+    pub forges: Vec<ForgeConfig>,
 }
 
 impl Default for Config {
@@ -35,20 +35,31 @@ impl Default for Config {
             filter: FilterCriteria::default(),
             tui: TuiConfig::default(),
             git: GitConfig::default(),
-            github: GitHubConfig::default(),
+            forges: vec![ForgeConfig::default()],
         }
     }
 }
 
 impl Config {
-    /// Load configuration from file
+    /// Load configuration from file, resolving any `!env`-tagged secrets
     pub fn load(path: &PathBuf) -> Result<Self> {
         let content = std::fs::read_to_string(path)?;
-        let config: Config = toml::from_str(&content)
+        let mut config: Config = toml::from_str(&content)
             .map_err(|e| Error::Config(format!("Failed to parse config: {}", e)))?;
+        config.resolve_secrets()?;
         Ok(config)
     }
 
+    /// Resolve `!env VAR` / `{ env = "VAR" }` secrets against the process environment
+    fn resolve_secrets(&mut self) -> Result<()> {
+        for forge in &mut self.forges {
+            if let Some(token) = &forge.token {
+                forge.token = Some(SecretValue::Literal(token.resolve()?));
+            }
+        }
+        Ok(())
+    }
+
     /// Save configuration to file
     pub fn save(&self, path: &PathBuf) -> Result<()> {
         let content = toml::to_string_pretty(self)
@@ -73,16 +84,51 @@ impl Config {
             Ok(Self::default())
         }
     }
+
+    /// Look up the [`ForgeConfig`] that hosts `org`, following `general.orgs`
+    ///
+    /// Falls back to the first configured forge if `org` isn't listed, so a
+    /// single-org, single-forge config keeps working without an explicit
+    /// `[[general.orgs]]` entry.
+    pub fn forge_for_org(&self, org: &str) -> Option<&ForgeConfig> {
+        let forge_name = self
+            .general
+            .orgs
+            .iter()
+            .find(|o| o.name == org)
+            .map(|o| o.forge.as_str());
+
+        match forge_name {
+            Some(name) => self.forges.iter().find(|f| f.name == name),
+            None => self.forges.first(),
+        }
+    }
+}
+
+/// Maps a GitHub/Forgejo/Gitea organization to the named [`ForgeConfig`] entry
+/// that hosts it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrgConfig {
+    /// Organization (or owner) name on the forge
+    pub name: String,
+
+    /// Name of the [`ForgeConfig`] entry this organization lives on
+    pub forge: String,
 }
 
 /// General configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GeneralConfig {
-    /// GitHub organization name
+    /// Default organization name, used when no `--org` is given
     pub org: String,
 
     /// Workspace root directory
     pub workspace_root: PathBuf,
+
+    /// Organizations managed by this workspace, mapped to the forge that
+    /// hosts each one. An org with no entry here falls back to the first
+    /// configured forge.
+    pub orgs: Vec<OrgConfig>,
 }
 
 impl Default for GeneralConfig {
@@ -91,6 +137,10 @@ impl Default for GeneralConfig {
         Self {
             org: "raibid-labs".to_string(),
             workspace_root: home.join("raibid-labs"),
+            orgs: vec![OrgConfig {
+                name: "raibid-labs".to_string(),
+                forge: "github".to_string(),
+            }],
         }
     }
 }
@@ -167,6 +217,16 @@ impl Default for KeyBindings {
     }
 }
 
+/// Which [`crate::git::GitBackend`] implementation to use for clone/pull
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GitBackendKind {
+    /// libgit2, the default
+    Git2,
+    /// Shell out to the system `git` binary
+    Cli,
+}
+
 /// Git configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GitConfig {
@@ -175,6 +235,9 @@ pub struct GitConfig {
 
     /// Clone depth (0 = full clone)
     pub depth: u32,
+
+    /// Which backend implementation performs clone/pull operations
+    pub backend: GitBackendKind,
 }
 
 impl Default for GitConfig {
@@ -182,25 +245,80 @@ impl Default for GitConfig {
         Self {
             ssh_auth: true,
             depth: 0,
+            backend: GitBackendKind::Git2,
+        }
+    }
+}
+
+/// A config value that may be a literal or sourced from an environment variable
+///
+/// Accepts either `token = { env = "GITHUB_TOKEN" }` or the shorthand string
+/// form `token = "!env GITHUB_TOKEN"`, so secrets can live in `.env`/CI
+/// secrets instead of being committed to `config.toml`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SecretValue {
+    Literal(String),
+    Env { env: String },
+}
+
+impl SecretValue {
+    /// Resolve to the underlying value, reading from the environment if needed
+    pub fn resolve(&self) -> Result<String> {
+        match self {
+            SecretValue::Literal(value) => match value.strip_prefix("!env ") {
+                Some(var) => Self::read_env(var.trim()),
+                None => Ok(value.clone()),
+            },
+            SecretValue::Env { env } => Self::read_env(env),
         }
     }
+
+    fn read_env(var: &str) -> Result<String> {
+        std::env::var(var)
+            .map_err(|_| Error::Config(format!("environment variable `{}` is not set", var)))
+    }
 }
 
-/// GitHub configuration
+/// Which forge backend a [`ForgeConfig`] entry talks to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ForgeKind {
+    GitHub,
+    Forgejo,
+    Gitea,
+}
+
+/// A single configured forge endpoint (GitHub, Forgejo, or Gitea)
+///
+/// `Repository::forge` records which entry's `name` a repository came from,
+/// so `sync` can look the entry back up to pick the right clone URL and
+/// credentials.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GitHubConfig {
-    /// GitHub API token (optional, usually from gh cli)
-    pub token: Option<String>,
+pub struct ForgeConfig {
+    /// Unique name for this forge entry
+    pub name: String,
+
+    /// Which backend this entry talks to
+    #[serde(rename = "type")]
+    pub kind: ForgeKind,
 
-    /// API base URL
-    pub api_url: String,
+    /// Base API endpoint, e.g. `https://api.github.com` or
+    /// `https://git.example.com/api/v1`
+    pub endpoint: String,
+
+    /// Authentication token (optional; requests are sent unauthenticated without one).
+    /// Supports `!env VAR` / `{ env = "VAR" }` to source it from the environment.
+    pub token: Option<SecretValue>,
 }
 
-impl Default for GitHubConfig {
+impl Default for ForgeConfig {
     fn default() -> Self {
         Self {
+            name: "github".to_string(),
+            kind: ForgeKind::GitHub,
+            endpoint: "https://api.github.com".to_string(),
             token: None,
-            api_url: "https://api.github.com".to_string(),
         }
     }
 }
@@ -223,4 +341,51 @@ mod tests {
         let parsed: Config = toml::from_str(&toml_str).unwrap();
         assert_eq!(parsed.general.org, config.general.org);
     }
+
+    #[test]
+    fn test_forge_for_org_mapped() {
+        let config = Config::default();
+        let forge = config.forge_for_org("raibid-labs").unwrap();
+        assert_eq!(forge.name, "github");
+    }
+
+    #[test]
+    fn test_forge_for_org_falls_back_to_first_forge() {
+        let config = Config::default();
+        let forge = config.forge_for_org("some-other-org").unwrap();
+        assert_eq!(forge.name, "github");
+    }
+
+    #[test]
+    fn test_secret_value_literal() {
+        let secret = SecretValue::Literal("ghp_abc123".to_string());
+        assert_eq!(secret.resolve().unwrap(), "ghp_abc123");
+    }
+
+    #[test]
+    fn test_secret_value_env() {
+        std::env::set_var("RAIBID_TEST_TOKEN", "from-env");
+        let secret = SecretValue::Env {
+            env: "RAIBID_TEST_TOKEN".to_string(),
+        };
+        assert_eq!(secret.resolve().unwrap(), "from-env");
+        std::env::remove_var("RAIBID_TEST_TOKEN");
+    }
+
+    #[test]
+    fn test_secret_value_bang_env_shorthand() {
+        std::env::set_var("RAIBID_TEST_TOKEN_2", "also-from-env");
+        let secret = SecretValue::Literal("!env RAIBID_TEST_TOKEN_2".to_string());
+        assert_eq!(secret.resolve().unwrap(), "also-from-env");
+        std::env::remove_var("RAIBID_TEST_TOKEN_2");
+    }
+
+    #[test]
+    fn test_secret_value_missing_env_errors() {
+        std::env::remove_var("RAIBID_TEST_TOKEN_MISSING");
+        let secret = SecretValue::Env {
+            env: "RAIBID_TEST_TOKEN_MISSING".to_string(),
+        };
+        assert!(secret.resolve().is_err());
+    }
 }
@@ -1,5 +1,6 @@
 //! Error types for raibid-core
 
+use chrono::{DateTime, Utc};
 use thiserror::Error;
 
 /// Result type alias using our Error type
@@ -26,6 +27,12 @@ pub enum Error {
     #[error("Authentication failed: {0}")]
     Authentication(String),
 
+    #[error("Repository diverged from upstream: {0}")]
+    Diverged(String),
+
+    #[error("GitHub API rate limit exceeded, resets at {reset_at}")]
+    RateLimited { reset_at: DateTime<Utc> },
+
     #[error("Network error: {0}")]
     Network(#[from] reqwest::Error),
 
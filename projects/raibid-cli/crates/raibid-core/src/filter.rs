@@ -68,9 +68,123 @@ pub fn filter_repositories(repos: Vec<Repository>, criteria: &FilterCriteria) ->
         });
     }
 
+    // Apply include regex patterns
+    if !criteria.include_regex.is_empty() {
+        let patterns = compile_regexes(&criteria.include_regex)?;
+        filtered.retain(|r| {
+            patterns.iter().any(|p| p.is_match(&r.name) || p.is_match(&r.full_name))
+        });
+    }
+
+    // Apply exclude regex patterns
+    if !criteria.exclude_regex.is_empty() {
+        let patterns = compile_regexes(&criteria.exclude_regex)?;
+        filtered.retain(|r| {
+            !patterns.iter().any(|p| p.is_match(&r.name) || p.is_match(&r.full_name))
+        });
+    }
+
+    // Apply topics_any filter (case-insensitive)
+    if !criteria.topics_any.is_empty() {
+        let wanted: Vec<String> = criteria.topics_any.iter().map(|t| t.to_lowercase()).collect();
+        filtered.retain(|r| {
+            r.topics
+                .iter()
+                .any(|t| wanted.contains(&t.to_lowercase()))
+        });
+    }
+
+    // Apply topics_all filter (case-insensitive)
+    if !criteria.topics_all.is_empty() {
+        let wanted: Vec<String> = criteria.topics_all.iter().map(|t| t.to_lowercase()).collect();
+        filtered.retain(|r| {
+            let repo_topics: Vec<String> = r.topics.iter().map(|t| t.to_lowercase()).collect();
+            wanted.iter().all(|t| repo_topics.contains(t))
+        });
+    }
+
+    // Apply fuzzy match, ranking by score (descending) then stars (descending)
+    if let Some(ref query) = criteria.fuzzy {
+        let mut scored: Vec<(i64, Repository)> = filtered
+            .into_iter()
+            .filter_map(|r| {
+                let score = fuzzy_score(query, &r.name)
+                    .into_iter()
+                    .chain(fuzzy_score(query, &r.full_name))
+                    .max()?;
+                Some((score, r))
+            })
+            .collect();
+
+        scored.sort_by(|a, b| {
+            b.0.cmp(&a.0)
+                .then_with(|| b.1.stargazers_count.cmp(&a.1.stargazers_count))
+        });
+
+        filtered = scored.into_iter().map(|(_, r)| r).collect();
+    }
+
     Ok(filtered)
 }
 
+/// Compile a list of regex patterns, surfacing a bad expression as `Error::InvalidFilter`
+fn compile_regexes(patterns: &[String]) -> Result<Vec<Regex>> {
+    patterns
+        .iter()
+        .map(|p| Regex::new(p).map_err(|e| Error::InvalidFilter(e.to_string())))
+        .collect()
+}
+
+/// Score `candidate` against `query` as an fzf-style subsequence match
+///
+/// Every (lowercased) character of `query` must appear in `candidate`, in
+/// order, but not necessarily contiguously. Returns `None` when `query`
+/// isn't a subsequence of `candidate`. Higher scores reward consecutive
+/// runs and matches starting right after a `-`, `_`, `/` boundary (or at
+/// the very start of the string).
+fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    const CONSECUTIVE_BONUS: i64 = 15;
+    const BOUNDARY_BONUS: i64 = 10;
+    const GAP_PENALTY: i64 = 1;
+
+    let query = query.to_lowercase();
+    let candidate_lower = candidate.to_lowercase();
+    let chars: Vec<char> = candidate_lower.chars().collect();
+
+    let mut score: i64 = 0;
+    let mut last_match: Option<usize> = None;
+    let mut cand_idx = 0;
+
+    for q in query.chars() {
+        let mut found = None;
+        while cand_idx < chars.len() {
+            if chars[cand_idx] == q {
+                found = Some(cand_idx);
+                break;
+            }
+            cand_idx += 1;
+        }
+
+        let idx = found?;
+
+        match last_match {
+            Some(prev) if idx == prev + 1 => score += CONSECUTIVE_BONUS,
+            Some(prev) => score -= GAP_PENALTY * (idx - prev - 1) as i64,
+            None => {}
+        }
+
+        let at_boundary = idx == 0 || matches!(chars[idx - 1], '-' | '_' | '/');
+        if at_boundary {
+            score += BOUNDARY_BONUS;
+        }
+
+        last_match = Some(idx);
+        cand_idx += 1;
+    }
+
+    Some(score)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -93,6 +207,7 @@ mod tests {
             updated_at: Utc::now(),
             pushed_at: Utc::now(),
             topics: vec![],
+            forge: "github".to_string(),
         }
     }
 
@@ -180,4 +295,158 @@ mod tests {
         assert_eq!(filtered.len(), 1);
         assert_eq!(filtered[0].name, "other-repo");
     }
+
+    fn with_topics(mut repo: Repository, topics: &[&str]) -> Repository {
+        repo.topics = topics.iter().map(|t| t.to_string()).collect();
+        repo
+    }
+
+    #[test]
+    fn test_filter_topics_any() {
+        let repos = vec![
+            with_topics(create_test_repo("repo1", false, false, 10), &["rust", "cli"]),
+            with_topics(create_test_repo("repo2", false, false, 20), &["python"]),
+        ];
+
+        let criteria = FilterCriteria {
+            topics_any: vec!["Rust".to_string(), "go".to_string()],
+            ..Default::default()
+        };
+
+        let filtered = filter_repositories(repos, &criteria).unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].name, "repo1");
+    }
+
+    #[test]
+    fn test_filter_topics_all() {
+        let repos = vec![
+            with_topics(create_test_repo("repo1", false, false, 10), &["rust", "library"]),
+            with_topics(create_test_repo("repo2", false, false, 20), &["rust"]),
+        ];
+
+        let criteria = FilterCriteria {
+            topics_all: vec!["RUST".to_string(), "Library".to_string()],
+            ..Default::default()
+        };
+
+        let filtered = filter_repositories(repos, &criteria).unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].name, "repo1");
+    }
+
+    #[test]
+    fn test_filter_include_regex() {
+        let repos = vec![
+            create_test_repo("infra-prod-api", false, false, 10),
+            create_test_repo("docs-site", false, false, 20),
+        ];
+
+        let criteria = FilterCriteria {
+            include_regex: vec!["^infra-(prod|staging)-.*$".to_string()],
+            ..Default::default()
+        };
+
+        let filtered = filter_repositories(repos, &criteria).unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].name, "infra-prod-api");
+    }
+
+    #[test]
+    fn test_filter_exclude_regex() {
+        let repos = vec![
+            create_test_repo("infra-prod-api", false, false, 10),
+            create_test_repo("docs-site", false, false, 20),
+        ];
+
+        let criteria = FilterCriteria {
+            exclude_regex: vec!["^infra-.*$".to_string()],
+            ..Default::default()
+        };
+
+        let filtered = filter_repositories(repos, &criteria).unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].name, "docs-site");
+    }
+
+    #[test]
+    fn test_filter_invalid_regex_errors() {
+        let repos = vec![create_test_repo("repo1", false, false, 10)];
+
+        let criteria = FilterCriteria {
+            include_regex: vec!["(unclosed".to_string()],
+            ..Default::default()
+        };
+
+        assert!(filter_repositories(repos, &criteria).is_err());
+    }
+
+    #[test]
+    fn test_filter_fuzzy_subsequence_match() {
+        let repos = vec![
+            create_test_repo("raibid-core", false, false, 10),
+            create_test_repo("other-repo", false, false, 20),
+        ];
+
+        let criteria = FilterCriteria {
+            fuzzy: Some("rbdcr".to_string()),
+            ..Default::default()
+        };
+
+        let filtered = filter_repositories(repos, &criteria).unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].name, "raibid-core");
+    }
+
+    #[test]
+    fn test_filter_fuzzy_excludes_non_subsequence() {
+        let repos = vec![create_test_repo("raibid-core", false, false, 10)];
+
+        let criteria = FilterCriteria {
+            fuzzy: Some("zzz".to_string()),
+            ..Default::default()
+        };
+
+        let filtered = filter_repositories(repos, &criteria).unwrap();
+        assert!(filtered.is_empty());
+    }
+
+    #[test]
+    fn test_filter_fuzzy_ranks_better_match_first() {
+        let repos = vec![
+            create_test_repo("xyzcore", false, false, 10),
+            create_test_repo("core", false, false, 10),
+        ];
+
+        let criteria = FilterCriteria {
+            fuzzy: Some("core".to_string()),
+            ..Default::default()
+        };
+
+        let filtered = filter_repositories(repos, &criteria).unwrap();
+        assert_eq!(filtered[0].name, "core");
+    }
+
+    #[test]
+    fn test_filter_fuzzy_tie_breaks_by_stars() {
+        let repos = vec![
+            create_test_repo("core", false, false, 5),
+            create_test_repo("core2", false, false, 50),
+        ];
+
+        let criteria = FilterCriteria {
+            fuzzy: Some("core".to_string()),
+            ..Default::default()
+        };
+
+        let filtered = filter_repositories(repos, &criteria).unwrap();
+        assert_eq!(filtered[0].name, "core2");
+    }
+
+    #[test]
+    fn test_fuzzy_score_rewards_boundary_start() {
+        let boundary = fuzzy_score("c", "a-core").unwrap();
+        let mid_word = fuzzy_score("c", "axcore").unwrap();
+        assert!(boundary > mid_word);
+    }
 }
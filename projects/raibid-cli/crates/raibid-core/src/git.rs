@@ -4,23 +4,216 @@ use crate::error::{Error, Result};
 use crate::types::{LocalRepoState, Repository};
 use git2::{Repository as Git2Repository, StatusOptions};
 use std::path::{Path, PathBuf};
+use std::process::Command;
 use tracing::{debug, info};
 
-/// Git operations handler
-pub struct GitOps;
+/// Credentials available for a clone/pull operation
+///
+/// Tried in order by [`Git2Backend`]: an SSH agent, then an explicit private
+/// key path, then an HTTPS token. `CliGitBackend` only honors
+/// `ssh_key_path`/`https_token`, since the system `git` already consults the
+/// user's SSH agent and credential helpers on its own.
+#[derive(Debug, Clone, Default)]
+pub struct Credentials {
+    /// Explicit SSH private key path, tried after the SSH agent
+    pub ssh_key_path: Option<PathBuf>,
 
-impl GitOps {
-    /// Check if a path contains a valid git repository
-    pub fn is_git_repo(path: &Path) -> bool {
-        Git2Repository::open(path).is_ok()
-    }
+    /// Passphrase for an encrypted SSH private key
+    pub ssh_key_passphrase: Option<String>,
+
+    /// HTTPS token (e.g. a forge's personal access token)
+    pub https_token: Option<String>,
+}
 
+/// Outcome of a successful [`GitBackend::pull`]
+#[derive(Debug, Clone, Default)]
+pub struct PullOutcome {
+    /// Whether the local branch was fast-forwarded to the upstream tip
+    pub fast_forwarded: bool,
+
+    /// Number of commits fetched from upstream (the `behind` count at fetch time)
+    pub commits_fetched: usize,
+}
+
+/// Backend-agnostic git operations
+///
+/// Implemented by [`Git2Backend`] (the default, using libgit2) and
+/// [`CliGitBackend`] (shelling out to the system `git` binary). The CLI
+/// backend matters for repos that rely on `.gitconfig` includes, credential
+/// helpers, partial-clone filters, or custom transports that libgit2 can't
+/// handle, and it honors the user's existing `~/.ssh/config`.
+pub trait GitBackend: Send + Sync {
     /// Get the state of a local repository
-    pub fn get_local_state(path: &Path) -> Result<LocalRepoState> {
+    fn get_local_state(&self, path: &Path) -> Result<LocalRepoState>;
+
+    /// Clone a repository
+    fn clone(&self, url: &str, path: &Path, depth: u32, credentials: &Credentials) -> Result<()>;
+
+    /// Fetch `origin` and fast-forward the current branch to its upstream
+    ///
+    /// Returns [`Error::Diverged`] instead of fast-forwarding if the local
+    /// branch and its upstream have both moved.
+    fn pull(&self, path: &Path, credentials: &Credentials) -> Result<PullOutcome>;
+
+    /// Get repository URL
+    fn get_remote_url(&self, path: &Path) -> Result<String>;
+}
+
+/// Map a git2 error to [`Error::Authentication`] when it's an auth failure,
+/// otherwise pass it through as a generic [`Error::Git`]
+fn map_git_error(e: git2::Error) -> Error {
+    match e.code() {
+        git2::ErrorCode::Auth => Error::Authentication(e.message().to_string()),
+        _ => Error::Git(e),
+    }
+}
+
+/// Build remote callbacks that try, in order: an SSH agent, an explicit
+/// private key path, then HTTPS token auth
+fn build_callbacks(credentials: &Credentials) -> git2::RemoteCallbacks<'_> {
+    let mut callbacks = git2::RemoteCallbacks::new();
+
+    callbacks.credentials(move |url, username_from_url, allowed_types| {
+        let username = username_from_url.unwrap_or("git");
+
+        if allowed_types.contains(git2::CredentialType::SSH_KEY) {
+            if let Ok(cred) = git2::Cred::ssh_key_from_agent(username) {
+                return Ok(cred);
+            }
+
+            if let Some(key_path) = &credentials.ssh_key_path {
+                if let Ok(cred) = git2::Cred::ssh_key(
+                    username,
+                    None,
+                    key_path,
+                    credentials.ssh_key_passphrase.as_deref(),
+                ) {
+                    return Ok(cred);
+                }
+            }
+        }
+
+        if allowed_types.contains(git2::CredentialType::USER_PASS_PLAINTEXT) {
+            if let Some(token) = &credentials.https_token {
+                return git2::Cred::userpass_plaintext(token, "");
+            }
+        }
+
+        Err(git2::Error::from_str(&format!(
+            "no usable credentials available for {}",
+            url
+        )))
+    });
+
+    callbacks
+}
+
+/// Check if a path contains a valid git repository
+pub fn is_git_repo(path: &Path) -> bool {
+    Git2Repository::open(path).is_ok()
+}
+
+/// Disambiguates concurrent [`write_askpass_script`] calls within one process
+static ASKPASS_COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+/// Write a short-lived `GIT_ASKPASS` helper script that supplies an HTTPS
+/// token without ever placing it on the command line or in the clone URL
+///
+/// `git` invokes the script once per credential prompt, passing the prompt
+/// text (e.g. `"Username for 'https://github.com': "`) as `argv[1]`. We
+/// answer the username prompt with the token (as GitHub/Forgejo/Gitea all
+/// accept a PAT as the HTTPS username) and the password prompt with nothing.
+/// The token itself is read from `RAIBID_GIT_ASKPASS_TOKEN`, an environment
+/// variable set on the `git` child process rather than passed as an
+/// argument, so it never shows up in `ps`/`/proc/<pid>/cmdline`. The path is
+/// suffixed with a per-process counter, not just the PID, so two overlapping
+/// clones/pulls on the sync engine's `concurrency > 1` path each get their
+/// own file instead of racing to remove one shared script out from under
+/// each other. The caller is responsible for removing the returned path once
+/// `git` has exited.
+fn write_askpass_script() -> Result<PathBuf> {
+    use std::os::unix::fs::PermissionsExt;
+    use std::sync::atomic::Ordering;
+
+    let unique = ASKPASS_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let path = std::env::temp_dir().join(format!(
+        "raibid-askpass-{}-{}",
+        std::process::id(),
+        unique
+    ));
+    std::fs::write(
+        &path,
+        "#!/bin/sh\ncase \"$1\" in\n  Username*) printf '%s' \"$RAIBID_GIT_ASKPASS_TOKEN\" ;;\n  *) printf '' ;;\nesac\n",
+    )?;
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o700))?;
+    Ok(path)
+}
+
+/// Single-quote `value` for safe interpolation into a shell command string
+///
+/// `git` word-splits `$GIT_SSH_COMMAND` itself, so an unquoted path
+/// containing a space (e.g. `~/My Keys/id_rsa`) silently turns into two
+/// arguments instead of one. Wraps in single quotes, escaping any embedded
+/// `'` as `'\''`.
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// The current branch's tracking state relative to its upstream
+struct UpstreamState {
+    branch_name: String,
+    local_oid: git2::Oid,
+    upstream_oid: git2::Oid,
+    ahead: usize,
+    behind: usize,
+}
+
+/// Resolve HEAD's upstream tracking branch and how far it has diverged
+///
+/// Returns `Ok(None)` when HEAD isn't a branch or has no upstream configured.
+fn upstream_state(repo: &Git2Repository) -> Result<Option<UpstreamState>> {
+    let head = repo.head()?;
+    if !head.is_branch() {
+        return Ok(None);
+    }
+    let Some(branch_name) = head.shorthand() else {
+        return Ok(None);
+    };
+    let branch_name = branch_name.to_string();
+    let Some(local_oid) = head.target() else {
+        return Ok(None);
+    };
+
+    let branch = repo.find_branch(&branch_name, git2::BranchType::Local)?;
+    let upstream = match branch.upstream() {
+        Ok(upstream) => upstream,
+        Err(_) => return Ok(None),
+    };
+    let Some(upstream_oid) = upstream.get().target() else {
+        return Ok(None);
+    };
+
+    let (ahead, behind) = repo.graph_ahead_behind(local_oid, upstream_oid)?;
+
+    Ok(Some(UpstreamState {
+        branch_name,
+        local_oid,
+        upstream_oid,
+        ahead,
+        behind,
+    }))
+}
+
+/// libgit2-backed implementation of [`GitBackend`]
+#[derive(Debug, Default)]
+pub struct Git2Backend;
+
+impl GitBackend for Git2Backend {
+    fn get_local_state(&self, path: &Path) -> Result<LocalRepoState> {
         let exists = path.exists();
-        let is_git_repo = exists && Self::is_git_repo(path);
+        let is_repo = exists && is_git_repo(path);
 
-        if !is_git_repo {
+        if !is_repo {
             return Ok(LocalRepoState {
                 path: path.to_path_buf(),
                 exists,
@@ -37,9 +230,7 @@ impl GitOps {
 
         // Get current branch
         let head = repo.head()?;
-        let current_branch = head
-            .shorthand()
-            .map(|s| s.to_string());
+        let current_branch = head.shorthand().map(|s| s.to_string());
 
         // Check for uncommitted changes
         let mut opts = StatusOptions::new();
@@ -47,53 +238,104 @@ impl GitOps {
         let statuses = repo.statuses(Some(&mut opts))?;
         let has_uncommitted_changes = !statuses.is_empty();
 
+        let tracking = upstream_state(&repo)?;
+
         Ok(LocalRepoState {
             path: path.to_path_buf(),
             exists: true,
             is_git_repo: true,
             current_branch,
             has_uncommitted_changes,
-            commits_behind: None, // TODO: Calculate from remote
-            commits_ahead: None,  // TODO: Calculate from remote
-            last_sync: None,      // TODO: Track last sync time
+            commits_behind: tracking.as_ref().map(|t| t.behind),
+            commits_ahead: tracking.as_ref().map(|t| t.ahead),
+            last_sync: None, // TODO: Track last sync time
         })
     }
 
-    /// Clone a repository
-    pub fn clone(url: &str, path: &Path, depth: u32) -> Result<()> {
+    fn clone(&self, url: &str, path: &Path, depth: u32, credentials: &Credentials) -> Result<()> {
         info!("Cloning {} to {}", url, path.display());
 
         let mut builder = git2::build::RepoBuilder::new();
 
+        let mut fetch_options = git2::FetchOptions::new();
+        fetch_options.remote_callbacks(build_callbacks(credentials));
         if depth > 0 {
-            let mut fetch_options = git2::FetchOptions::new();
             fetch_options.depth(depth as i32);
-            builder.fetch_options(fetch_options);
         }
+        builder.fetch_options(fetch_options);
 
-        builder.clone(url, path)?;
+        builder.clone(url, path).map_err(map_git_error)?;
 
         debug!("Successfully cloned {}", url);
         Ok(())
     }
 
-    /// Pull updates for a repository
-    pub fn pull(path: &Path) -> Result<()> {
+    fn pull(&self, path: &Path, credentials: &Credentials) -> Result<PullOutcome> {
         info!("Pulling updates for {}", path.display());
 
         let repo = Git2Repository::open(path)?;
 
-        // TODO: Implement proper pull operation
-        // For now, just fetch
-        let mut remote = repo.find_remote("origin")?;
-        remote.fetch(&["HEAD"], None, None)?;
+        {
+            let mut remote = repo.find_remote("origin")?;
+            let mut fetch_options = git2::FetchOptions::new();
+            fetch_options.remote_callbacks(build_callbacks(credentials));
+            // An empty refspec list falls back to the remote's configured
+            // fetch refspecs (e.g. `+refs/heads/*:refs/remotes/origin/*`),
+            // which actually advances the remote-tracking ref. A bare
+            // `"HEAD"` refspec has no `:` destination, so it only ever
+            // updates `FETCH_HEAD` and leaves `upstream_state` reading stale
+            // data below.
+            remote
+                .fetch(&[] as &[&str], Some(&mut fetch_options), None)
+                .map_err(map_git_error)?;
+        }
 
-        debug!("Successfully pulled updates for {}", path.display());
-        Ok(())
+        let Some(tracking) = upstream_state(&repo)? else {
+            debug!("{} has no upstream configured, nothing to merge", path.display());
+            return Ok(PullOutcome::default());
+        };
+
+        if tracking.behind == 0 {
+            debug!("{} already up to date", path.display());
+            return Ok(PullOutcome::default());
+        }
+
+        if tracking.ahead > 0 {
+            return Err(Error::Diverged(format!(
+                "{} is {} commit(s) ahead and {} behind upstream",
+                path.display(),
+                tracking.ahead,
+                tracking.behind
+            )));
+        }
+
+        // Fast-forward: move the branch ref to upstream and update the working tree
+        let upstream_commit = repo.find_commit(tracking.upstream_oid)?;
+        let refname = format!("refs/heads/{}", tracking.branch_name);
+        repo.reference(
+            &refname,
+            tracking.upstream_oid,
+            true,
+            &format!("fast-forward: {} -> {}", tracking.local_oid, tracking.upstream_oid),
+        )?;
+        repo.set_head(&refname)?;
+        repo.checkout_tree(
+            upstream_commit.as_object(),
+            Some(git2::build::CheckoutBuilder::new().force()),
+        )?;
+
+        debug!(
+            "Fast-forwarded {} by {} commit(s)",
+            path.display(),
+            tracking.behind
+        );
+        Ok(PullOutcome {
+            fast_forwarded: true,
+            commits_fetched: tracking.behind,
+        })
     }
 
-    /// Get repository URL
-    pub fn get_remote_url(path: &Path) -> Result<String> {
+    fn get_remote_url(&self, path: &Path) -> Result<String> {
         let repo = Git2Repository::open(path)?;
         let remote = repo.find_remote("origin")?;
         remote
@@ -103,11 +345,229 @@ impl GitOps {
     }
 }
 
+/// Shell-out implementation of [`GitBackend`] using the system `git` binary
+#[derive(Debug, Default)]
+pub struct CliGitBackend;
+
+impl CliGitBackend {
+    /// Run `git <args>` optionally scoped to `dir`, returning trimmed stdout
+    fn run(dir: Option<&Path>, args: &[&str]) -> Result<String> {
+        let mut cmd = Command::new("git");
+        if let Some(dir) = dir {
+            cmd.arg("-C").arg(dir);
+        }
+        cmd.args(args);
+
+        let output = cmd.output().map_err(|e| {
+            Error::Git(git2::Error::from_str(&format!(
+                "failed to run git: {}",
+                e
+            )))
+        })?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(Error::Git(git2::Error::from_str(stderr.trim())));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    /// Run `git <args>`, honoring an explicit SSH key via `GIT_SSH_COMMAND`
+    /// and an HTTPS token via a short-lived `GIT_ASKPASS` helper
+    ///
+    /// Without an explicit key, `git` already falls back to the user's SSH
+    /// agent and `~/.ssh/config` on its own. The token never touches argv or
+    /// the clone URL: it's handed to the askpass helper through an
+    /// environment variable, which keeps it out of `ps`/`/proc/<pid>/cmdline`
+    /// for the lifetime of the process.
+    fn run_with_credentials(
+        dir: Option<&Path>,
+        args: &[&str],
+        credentials: &Credentials,
+    ) -> Result<String> {
+        let mut cmd = Command::new("git");
+        if let Some(dir) = dir {
+            cmd.arg("-C").arg(dir);
+        }
+        cmd.args(args);
+
+        if let Some(key_path) = &credentials.ssh_key_path {
+            cmd.env(
+                "GIT_SSH_COMMAND",
+                format!(
+                    "ssh -i {} -o IdentitiesOnly=yes",
+                    shell_quote(&key_path.display().to_string())
+                ),
+            );
+        }
+
+        let askpass = match &credentials.https_token {
+            Some(token) => {
+                let path = write_askpass_script()?;
+                cmd.env("GIT_ASKPASS", &path);
+                cmd.env("RAIBID_GIT_ASKPASS_TOKEN", token);
+                Some(path)
+            }
+            None => None,
+        };
+
+        let output = cmd.output().map_err(|e| {
+            Error::Git(git2::Error::from_str(&format!(
+                "failed to run git: {}",
+                e
+            )))
+        });
+
+        if let Some(askpass) = askpass {
+            let _ = std::fs::remove_file(askpass);
+        }
+        let output = output?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(Error::Git(git2::Error::from_str(stderr.trim())));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    /// How far HEAD is ahead/behind its upstream, or `(None, None)` if HEAD
+    /// has no upstream configured
+    fn ahead_behind(path: &Path) -> Result<(Option<usize>, Option<usize>)> {
+        let Ok(upstream) = Self::run(
+            Some(path),
+            &["rev-parse", "--abbrev-ref", "--symbolic-full-name", "@{u}"],
+        ) else {
+            return Ok((None, None));
+        };
+
+        let counts = Self::run(
+            Some(path),
+            &["rev-list", "--left-right", "--count", &format!("HEAD...{}", upstream)],
+        )?;
+        let mut parts = counts.split_whitespace();
+        let ahead = parts.next().and_then(|s| s.parse().ok());
+        let behind = parts.next().and_then(|s| s.parse().ok());
+
+        Ok((ahead, behind))
+    }
+}
+
+impl GitBackend for CliGitBackend {
+    fn get_local_state(&self, path: &Path) -> Result<LocalRepoState> {
+        let exists = path.exists();
+        let is_repo = exists && is_git_repo(path);
+
+        if !is_repo {
+            return Ok(LocalRepoState {
+                path: path.to_path_buf(),
+                exists,
+                is_git_repo: false,
+                current_branch: None,
+                has_uncommitted_changes: false,
+                commits_behind: None,
+                commits_ahead: None,
+                last_sync: None,
+            });
+        }
+
+        let current_branch = Self::run(Some(path), &["rev-parse", "--abbrev-ref", "HEAD"]).ok();
+        let status = Self::run(Some(path), &["status", "--porcelain"])?;
+        let (ahead, behind) = Self::ahead_behind(path).unwrap_or((None, None));
+
+        Ok(LocalRepoState {
+            path: path.to_path_buf(),
+            exists: true,
+            is_git_repo: true,
+            current_branch,
+            has_uncommitted_changes: !status.is_empty(),
+            commits_behind: behind,
+            commits_ahead: ahead,
+            last_sync: None, // TODO: Track last sync time
+        })
+    }
+
+    fn clone(&self, url: &str, path: &Path, depth: u32, credentials: &Credentials) -> Result<()> {
+        info!("Cloning {} to {} via git CLI", url, path.display());
+
+        let depth_arg = depth.to_string();
+        let path_arg = path
+            .to_str()
+            .ok_or_else(|| Error::Git(git2::Error::from_str("non-utf8 destination path")))?;
+
+        let mut args = vec!["clone"];
+        if depth > 0 {
+            args.push("--depth");
+            args.push(&depth_arg);
+        }
+        args.push(url);
+        args.push(path_arg);
+
+        Self::run_with_credentials(None, &args, credentials)?;
+
+        debug!("Successfully cloned {}", url);
+        Ok(())
+    }
+
+    fn pull(&self, path: &Path, credentials: &Credentials) -> Result<PullOutcome> {
+        info!("Pulling updates for {} via git CLI", path.display());
+
+        Self::run_with_credentials(Some(path), &["fetch", "origin"], credentials)?;
+
+        let (ahead, behind) = match Self::ahead_behind(path)? {
+            (Some(ahead), Some(behind)) => (ahead, behind),
+            _ => {
+                debug!("{} has no upstream configured, nothing to merge", path.display());
+                return Ok(PullOutcome::default());
+            }
+        };
+
+        if behind == 0 {
+            debug!("{} already up to date", path.display());
+            return Ok(PullOutcome::default());
+        }
+
+        if ahead > 0 {
+            return Err(Error::Diverged(format!(
+                "{} is {} commit(s) ahead and {} behind upstream",
+                path.display(),
+                ahead,
+                behind
+            )));
+        }
+
+        let upstream = Self::run(
+            Some(path),
+            &["rev-parse", "--abbrev-ref", "--symbolic-full-name", "@{u}"],
+        )?;
+        Self::run(Some(path), &["merge", "--ff-only", &upstream])?;
+
+        debug!("Fast-forwarded {} by {} commit(s)", path.display(), behind);
+        Ok(PullOutcome {
+            fast_forwarded: true,
+            commits_fetched: behind,
+        })
+    }
+
+    fn get_remote_url(&self, path: &Path) -> Result<String> {
+        Self::run(Some(path), &["remote", "get-url", "origin"])
+    }
+}
+
 /// Helper to construct local repository path
 pub fn construct_repo_path(workspace_root: &Path, repo: &Repository) -> PathBuf {
     workspace_root.join(&repo.name)
 }
 
+/// Construct the configured [`GitBackend`] implementation
+pub fn build_backend(kind: crate::config::GitBackendKind) -> Box<dyn GitBackend> {
+    match kind {
+        crate::config::GitBackendKind::Git2 => Box::new(Git2Backend),
+        crate::config::GitBackendKind::Cli => Box::new(CliGitBackend),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,13 +575,13 @@ mod tests {
     #[test]
     fn test_is_git_repo_false() {
         let path = Path::new("/nonexistent/path");
-        assert!(!GitOps::is_git_repo(path));
+        assert!(!is_git_repo(path));
     }
 
     #[test]
     fn test_construct_repo_path() {
         let workspace = Path::new("/home/user/workspace");
-        let mut repo = Repository {
+        let repo = Repository {
             name: "test-repo".to_string(),
             full_name: "org/test-repo".to_string(),
             description: None,
@@ -137,9 +597,20 @@ mod tests {
             updated_at: chrono::Utc::now(),
             pushed_at: chrono::Utc::now(),
             topics: vec![],
+            forge: "github".to_string(),
         };
 
         let path = construct_repo_path(workspace, &repo);
         assert_eq!(path, Path::new("/home/user/workspace/test-repo"));
     }
+
+    #[test]
+    fn test_git2_backend_local_state_missing() {
+        let backend = Git2Backend;
+        let state = backend
+            .get_local_state(Path::new("/nonexistent/path"))
+            .unwrap();
+        assert!(!state.exists);
+        assert!(!state.is_git_repo);
+    }
 }
@@ -1,47 +1,155 @@
 //! GitHub API integration
 
 use crate::error::{Error, Result};
-use crate::types::Repository;
+use crate::types::{Issue, Repository};
+use chrono::{DateTime, TimeZone, Utc};
 use serde::Deserialize;
-use std::process::Command;
+use tracing::debug;
 
-/// GitHub API client
+const USER_AGENT: &str = concat!("raibid-cli/", env!("CARGO_PKG_VERSION"));
+const PER_PAGE: usize = 100;
+
+/// How many times [`GitHubClient::get`] will sleep-and-retry a rate-limited
+/// request before giving up and surfacing [`Error::RateLimited`]
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+
+/// GitHub API client, talking directly to the REST API over HTTPS
+///
+/// The `endpoint` only matters for GitHub Enterprise hosts: set it to
+/// something other than `https://api.github.com` to target a self-hosted
+/// instance's `/api/v3` root instead of github.com.
 pub struct GitHubClient {
-    org: String,
+    endpoint: String,
+    token: Option<String>,
+    client: reqwest::Client,
 }
 
 impl GitHubClient {
-    /// Create a new GitHub client for the specified organization
-    pub fn new(org: String) -> Self {
-        Self { org }
+    /// Create a new GitHub client for the given API endpoint
+    pub fn new(endpoint: String, token: Option<String>) -> Self {
+        Self {
+            endpoint,
+            token,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// List all repositories in an organization, following `Link` pagination
+    pub async fn list_repositories(&self, org: &str) -> Result<Vec<Repository>> {
+        let mut repos = Vec::new();
+        let mut url = Some(format!(
+            "{}/orgs/{}/repos?per_page={}",
+            self.endpoint, org, PER_PAGE
+        ));
+
+        while let Some(next) = url {
+            let resp = self.get(&next).await?;
+            url = next_page_url(resp.headers());
+            let batch: Vec<GitHubRepo> = resp.json().await?;
+            for repo in batch {
+                repos.push(repo.try_into()?);
+            }
+        }
+
+        Ok(repos)
+    }
+
+    /// Fetch a single repository by org and name
+    pub async fn get_repository(&self, org: &str, name: &str) -> Result<Repository> {
+        let url = format!("{}/repos/{}/{}", self.endpoint, org, name);
+        let resp = self.get(&url).await?;
+        let gh_repo: GitHubRepo = resp.json().await?;
+        gh_repo.try_into()
     }
 
-    /// List all repositories in the organization using gh CLI
-    pub async fn list_repositories(&self) -> Result<Vec<Repository>> {
-        // Use gh CLI to fetch repositories
-        let output = Command::new("gh")
-            .args([
-                "api",
-                &format!("/orgs/{}/repos", self.org),
-                "--paginate",
-                "--jq",
-                ".",
-            ])
-            .output()
-            .map_err(|e| Error::GitHub(format!("Failed to execute gh command: {}", e)))?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(Error::GitHub(format!("gh command failed: {}", stderr)));
+    /// List open issues and pull requests carrying `label` in `org/repo`,
+    /// following `Link` pagination
+    pub async fn list_issues(&self, org: &str, repo: &str, label: &str) -> Result<Vec<Issue>> {
+        let mut issues = Vec::new();
+        let mut url = Some(format!(
+            "{}/repos/{}/{}/issues?state=open&labels={}&per_page={}",
+            self.endpoint, org, repo, label, PER_PAGE
+        ));
+
+        while let Some(next) = url {
+            let resp = self.get(&next).await?;
+            url = next_page_url(resp.headers());
+            let batch: Vec<GitHubIssue> = resp.json().await?;
+            issues.extend(batch.into_iter().map(|i| i.into_issue(org, repo)));
         }
 
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let gh_repos: Vec<GitHubRepo> = serde_json::from_str(&stdout)?;
+        Ok(issues)
+    }
+
+    /// Issue an authenticated GET, sleeping until `X-RateLimit-Reset` and
+    /// retrying (up to [`MAX_RATE_LIMIT_RETRIES`] times) when rate-limited
+    ///
+    /// Surfaces [`Error::RateLimited`] if the limit is still in effect after
+    /// the retries are exhausted.
+    async fn get(&self, url: &str) -> Result<reqwest::Response> {
+        let mut attempt = 0;
+        loop {
+            let mut req = self
+                .client
+                .get(url)
+                .header("Accept", "application/vnd.github+json")
+                .header("User-Agent", USER_AGENT);
+            if let Some(token) = &self.token {
+                req = req.header("Authorization", format!("Bearer {}", token));
+            }
 
-        Ok(gh_repos.into_iter().map(|r| r.into()).collect())
+            let resp = req.send().await?;
+
+            if resp.status() == reqwest::StatusCode::FORBIDDEN && is_rate_limited(resp.headers()) {
+                let reset_at = rate_limit_reset(resp.headers()).unwrap_or_else(Utc::now);
+
+                if attempt >= MAX_RATE_LIMIT_RETRIES {
+                    return Err(Error::RateLimited { reset_at });
+                }
+
+                let wait = (reset_at - Utc::now()).to_std().unwrap_or_default();
+                debug!(
+                    "rate limited fetching {}, sleeping {:?} until reset (attempt {}/{})",
+                    url,
+                    wait,
+                    attempt + 1,
+                    MAX_RATE_LIMIT_RETRIES
+                );
+                tokio::time::sleep(wait).await;
+                attempt += 1;
+                continue;
+            }
+
+            return Ok(resp.error_for_status()?);
+        }
     }
 }
 
+/// Parse the next-page URL out of a GitHub `Link` response header
+fn next_page_url(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    let link = headers.get(reqwest::header::LINK)?.to_str().ok()?;
+    link.split(',').find_map(|part| {
+        let mut segments = part.split(';');
+        let url = segments.next()?.trim().trim_start_matches('<').trim_end_matches('>');
+        let is_next = segments.any(|s| s.trim() == "rel=\"next\"");
+        is_next.then(|| url.to_string())
+    })
+}
+
+fn is_rate_limited(headers: &reqwest::header::HeaderMap) -> bool {
+    headers
+        .get("x-ratelimit-remaining")
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v == "0")
+        .unwrap_or(false)
+}
+
+fn rate_limit_reset(headers: &reqwest::header::HeaderMap) -> Option<DateTime<Utc>> {
+    let reset = headers.get("x-ratelimit-reset")?.to_str().ok()?;
+    let epoch: i64 = reset.parse().ok()?;
+    Utc.timestamp_opt(epoch, 0).single()
+}
+
 /// GitHub API repository response
 #[derive(Debug, Deserialize)]
 struct GitHubRepo {
@@ -62,11 +170,17 @@ struct GitHubRepo {
     topics: Vec<String>,
 }
 
-impl From<GitHubRepo> for Repository {
-    fn from(gh: GitHubRepo) -> Self {
-        use chrono::DateTime;
+impl TryFrom<GitHubRepo> for Repository {
+    type Error = Error;
 
-        Self {
+    fn try_from(gh: GitHubRepo) -> Result<Self> {
+        let parse_timestamp = |field: &str, value: &str| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|e| Error::GitHub(format!("invalid `{}` timestamp: {}", field, e)))
+        };
+
+        Ok(Self {
             name: gh.name,
             full_name: gh.full_name,
             description: gh.description,
@@ -79,13 +193,60 @@ impl From<GitHubRepo> for Repository {
             language: gh.language,
             stargazers_count: gh.stargazers_count,
             forks_count: gh.forks_count,
-            updated_at: DateTime::parse_from_rfc3339(&gh.updated_at)
-                .unwrap()
-                .into(),
-            pushed_at: DateTime::parse_from_rfc3339(&gh.pushed_at)
-                .unwrap()
-                .into(),
+            updated_at: parse_timestamp("updated_at", &gh.updated_at)?,
+            pushed_at: parse_timestamp("pushed_at", &gh.pushed_at)?,
             topics: gh.topics,
+            forge: "github".to_string(),
+        })
+    }
+}
+
+/// GitHub API issue/PR response
+///
+/// GitHub nests a non-null `pull_request` object on issues that are
+/// actually pull requests; `merged_at` is only set once merged.
+#[derive(Debug, Deserialize)]
+struct GitHubIssue {
+    number: u64,
+    title: String,
+    html_url: String,
+    state: String,
+    updated_at: String,
+    #[serde(default)]
+    labels: Vec<GitHubLabel>,
+    pull_request: Option<GitHubPullRequestRef>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubLabel {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubPullRequestRef {
+    merged_at: Option<String>,
+}
+
+impl GitHubIssue {
+    fn into_issue(self, org: &str, repo: &str) -> Issue {
+        let updated_at = DateTime::parse_from_rfc3339(&self.updated_at)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now());
+
+        Issue {
+            repo_full_name: format!("{}/{}", org, repo),
+            number: self.number,
+            title: self.title,
+            url: self.html_url,
+            labels: self.labels.into_iter().map(|l| l.name).collect(),
+            is_pull_request: self.pull_request.is_some(),
+            merged: self
+                .pull_request
+                .as_ref()
+                .map(|pr| pr.merged_at.is_some())
+                .unwrap_or(false),
+            state: self.state,
+            updated_at,
         }
     }
 }
@@ -96,7 +257,73 @@ mod tests {
 
     #[test]
     fn test_github_client_creation() {
-        let client = GitHubClient::new("raibid-labs".to_string());
-        assert_eq!(client.org, "raibid-labs");
+        let client = GitHubClient::new("https://api.github.com".to_string(), None);
+        assert_eq!(client.endpoint, "https://api.github.com");
+    }
+
+    #[test]
+    fn test_try_from_invalid_timestamp_errors() {
+        let gh = GitHubRepo {
+            name: "repo".to_string(),
+            full_name: "org/repo".to_string(),
+            description: None,
+            clone_url: "https://github.com/org/repo".to_string(),
+            ssh_url: "git@github.com:org/repo.git".to_string(),
+            default_branch: "main".to_string(),
+            private: false,
+            fork: false,
+            archived: false,
+            language: None,
+            stargazers_count: 0,
+            forks_count: 0,
+            updated_at: "not-a-timestamp".to_string(),
+            pushed_at: "2024-01-01T00:00:00Z".to_string(),
+            topics: vec![],
+        };
+        assert!(Repository::try_from(gh).is_err());
+    }
+
+    #[test]
+    fn test_into_issue_detects_merged_pull_request() {
+        let gh = GitHubIssue {
+            number: 42,
+            title: "Fix thing".to_string(),
+            html_url: "https://github.com/org/repo/pull/42".to_string(),
+            state: "closed".to_string(),
+            updated_at: "2024-01-01T00:00:00Z".to_string(),
+            labels: vec![GitHubLabel {
+                name: "bug".to_string(),
+            }],
+            pull_request: Some(GitHubPullRequestRef {
+                merged_at: Some("2024-01-02T00:00:00Z".to_string()),
+            }),
+        };
+
+        let issue = gh.into_issue("org", "repo");
+        assert_eq!(issue.repo_full_name, "org/repo");
+        assert!(issue.is_pull_request);
+        assert!(issue.merged);
+        assert_eq!(issue.labels, vec!["bug".to_string()]);
+    }
+
+    #[test]
+    fn test_next_page_url_parses_link_header() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::LINK,
+            "<https://api.github.com/orgs/o/repos?page=2>; rel=\"next\", <https://api.github.com/orgs/o/repos?page=5>; rel=\"last\""
+                .parse()
+                .unwrap(),
+        );
+        assert_eq!(
+            next_page_url(&headers),
+            Some("https://api.github.com/orgs/o/repos?page=2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_next_page_url_missing_when_no_link_header() {
+        let headers = reqwest::header::HeaderMap::new();
+        assert_eq!(next_page_url(&headers), None);
     }
 }
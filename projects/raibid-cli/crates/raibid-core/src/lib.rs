@@ -3,21 +3,27 @@
 //! Core library for raibid-cli - meta-management tool for raibid-labs GitHub organization.
 //!
 //! This library provides the foundational functionality for:
-//! - GitHub API integration
+//! - Multi-forge API integration (GitHub, Forgejo, Gitea)
 //! - Git repository operations
 //! - Repository synchronization
 //! - Configuration management
 //! - Filtering and search
+//! - Label tracking and RSS feed generation
 
+pub mod cache;
 pub mod config;
 pub mod error;
 pub mod filter;
+pub mod forge;
 pub mod git;
 pub mod github;
 pub mod sync;
+pub mod track;
 pub mod types;
 
 // Re-export commonly used types
+pub use cache::RepoCache;
 pub use config::Config;
 pub use error::{Error, Result};
+pub use forge::Forge;
 pub use types::{Repository, SyncStatus};
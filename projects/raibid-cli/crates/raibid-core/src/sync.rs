@@ -1,25 +1,92 @@
 //! Repository synchronization engine
 
-use crate::error::Result;
-use crate::git::{construct_repo_path, GitOps};
+use crate::error::{Error, Result};
+use crate::git::{construct_repo_path, Credentials, GitBackend};
 use crate::types::{Repository, SyncOptions, SyncResult, SyncStatus};
 use std::path::Path;
+use std::sync::Arc;
 use std::time::Instant;
 use tracing::{error, info, warn};
 
 /// Synchronize a single repository
+///
+/// The actual clone/pull work runs inside [`tokio::task::spawn_blocking`]
+/// since [`GitBackend`] implementations shell out or call libgit2
+/// synchronously; `options.timeout`, if set, bounds how long we wait for
+/// that blocking work before reporting the repository as failed.
 pub async fn sync_repository(
     repo: &Repository,
     workspace_root: &Path,
     options: &SyncOptions,
+    backend: Arc<dyn GitBackend>,
 ) -> SyncResult {
     let start = Instant::now();
     let repo_path = construct_repo_path(workspace_root, repo);
+    let repo_owned = repo.clone();
+    let workspace_root = workspace_root.to_path_buf();
+    let options_owned = options.clone();
+
+    let task = tokio::task::spawn_blocking(move || {
+        sync_repository_blocking(&repo_owned, &workspace_root, &options_owned, backend.as_ref(), start)
+    });
+
+    let joined = match options.timeout {
+        Some(duration) => tokio::time::timeout(duration, task).await,
+        None => Ok(task.await),
+    };
+
+    match joined {
+        Ok(Ok(result)) => result,
+        Ok(Err(join_err)) => {
+            error!("Sync task for {} panicked: {}", repo.name, join_err);
+            SyncResult {
+                repository: repo.clone(),
+                status: SyncStatus::Failed,
+                path: repo_path,
+                error: Some(join_err.to_string()),
+                was_cloned: false,
+                commits_fetched: 0,
+                duration: start.elapsed(),
+            }
+        }
+        Err(_elapsed) => {
+            let duration = options.timeout.unwrap_or_default();
+            error!(
+                "Sync of {} timed out after {:?}",
+                repo.name, duration
+            );
+            SyncResult {
+                repository: repo.clone(),
+                status: SyncStatus::Failed,
+                path: repo_path,
+                error: Some(format!("Timed out after {:?}", duration)),
+                was_cloned: false,
+                commits_fetched: 0,
+                duration: start.elapsed(),
+            }
+        }
+    }
+}
+
+/// Synchronous clone/pull logic, run inside [`tokio::task::spawn_blocking`]
+fn sync_repository_blocking(
+    repo: &Repository,
+    workspace_root: &Path,
+    options: &SyncOptions,
+    backend: &dyn GitBackend,
+    start: Instant,
+) -> SyncResult {
+    let repo_path = construct_repo_path(workspace_root, repo);
+    let credentials = Credentials {
+        ssh_key_path: options.ssh_key_path.clone(),
+        ssh_key_passphrase: options.ssh_key_passphrase.clone(),
+        https_token: options.forge_tokens.get(&repo.forge).cloned(),
+    };
 
     info!("Syncing repository: {}", repo.name);
 
     // Check if repository exists locally
-    let local_state = match GitOps::get_local_state(&repo_path) {
+    let local_state = match backend.get_local_state(&repo_path) {
         Ok(state) => state,
         Err(e) => {
             error!("Failed to get local state for {}: {}", repo.name, e);
@@ -64,7 +131,7 @@ pub async fn sync_repository(
             &repo.clone_url
         };
 
-        match GitOps::clone(url, &repo_path, options.depth) {
+        match backend.clone(url, &repo_path, options.depth, &credentials) {
             Ok(_) => {
                 info!("Successfully cloned {}", repo.name);
                 SyncResult {
@@ -109,8 +176,8 @@ pub async fn sync_repository(
         }
 
         // Pull updates
-        match GitOps::pull(&repo_path) {
-            Ok(_) => {
+        match backend.pull(&repo_path, &credentials) {
+            Ok(outcome) => {
                 info!("Successfully synced {}", repo.name);
                 SyncResult {
                     repository: repo.clone(),
@@ -118,7 +185,19 @@ pub async fn sync_repository(
                     path: repo_path,
                     error: None,
                     was_cloned: false,
-                    commits_fetched: 0, // TODO: Track commits
+                    commits_fetched: outcome.commits_fetched,
+                    duration: start.elapsed(),
+                }
+            }
+            Err(Error::Diverged(msg)) => {
+                warn!("{} diverged from upstream: {}", repo.name, msg);
+                SyncResult {
+                    repository: repo.clone(),
+                    status: SyncStatus::Diverged,
+                    path: repo_path,
+                    error: Some(msg),
+                    was_cloned: false,
+                    commits_fetched: 0,
                     duration: start.elapsed(),
                 }
             }
@@ -143,6 +222,7 @@ pub async fn sync_repositories(
     repos: Vec<Repository>,
     workspace_root: &Path,
     options: &SyncOptions,
+    backend: Arc<dyn GitBackend>,
 ) -> Vec<SyncResult> {
     use futures::stream::{self, StreamExt};
 
@@ -156,7 +236,8 @@ pub async fn sync_repositories(
         .map(|repo| {
             let workspace_root = workspace_root.to_path_buf();
             let options = options.clone();
-            async move { sync_repository(&repo, &workspace_root, &options).await }
+            let backend = Arc::clone(&backend);
+            async move { sync_repository(&repo, &workspace_root, &options, backend).await }
         })
         .buffer_unordered(options.concurrency)
         .collect()
@@ -183,6 +264,7 @@ pub async fn sync_repositories(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::git::Git2Backend;
     use crate::types::Repository;
     use chrono::Utc;
 
@@ -203,6 +285,7 @@ mod tests {
             updated_at: Utc::now(),
             pushed_at: Utc::now(),
             topics: vec![],
+            forge: "github".to_string(),
         }
     }
 
@@ -215,7 +298,8 @@ mod tests {
             ..Default::default()
         };
 
-        let result = sync_repository(&repo, workspace.path(), &options).await;
+        let backend: Arc<dyn crate::git::GitBackend> = Arc::new(Git2Backend);
+        let result = sync_repository(&repo, workspace.path(), &options, backend).await;
 
         assert_eq!(result.status, SyncStatus::Pending);
         assert_eq!(result.was_cloned, false);
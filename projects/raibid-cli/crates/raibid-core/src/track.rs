@@ -0,0 +1,455 @@
+//! Label-tracking subsystem: polls issues/PRs across an org and emits RSS feeds
+//!
+//! Modeled on label-tracker: each repository is routed into one or more
+//! output "channels" by matching its `full_name` against a configured list
+//! of [`ChannelPattern`]s, and a small versioned [`TrackState`] file records
+//! which issue/PR actions have already been emitted so reruns only produce
+//! new RSS [`RssItem`]s with stable [`Guid`](RssItem::guid)s.
+
+use crate::error::{Error, Result};
+use crate::types::Issue;
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Maps repositories into named output channels via a regex on `full_name`
+#[derive(Debug, Clone)]
+struct ChannelPattern {
+    regex: Regex,
+    channels: Vec<String>,
+}
+
+impl ChannelPattern {
+    /// Parse a single entry of the form `regex:channel-a channel-b`
+    fn parse(spec: &str) -> Result<Self> {
+        let (pattern, channels) = spec.split_once(':').ok_or_else(|| {
+            Error::InvalidFilter(format!("channel pattern `{}` is missing a `:`", spec))
+        })?;
+        let regex = Regex::new(pattern).map_err(|e| Error::InvalidFilter(e.to_string()))?;
+        let channels = channels.split_whitespace().map(|s| s.to_string()).collect();
+        Ok(Self { regex, channels })
+    }
+
+    /// Channels this repo's `full_name` routes into, if the pattern matches it in full
+    fn channels_for(&self, full_name: &str) -> &[String] {
+        match self.regex.find(full_name) {
+            Some(m) if m.start() == 0 && m.end() == full_name.len() => &self.channels,
+            _ => &[],
+        }
+    }
+}
+
+/// An ordered set of [`ChannelPattern`]s mapping repos to output channels
+#[derive(Debug, Clone, Default)]
+pub struct ChannelPatterns(Vec<ChannelPattern>);
+
+impl ChannelPatterns {
+    /// Parse one `regex:channel-a channel-b` entry per (non-blank) line
+    pub fn parse(spec: &str) -> Result<Self> {
+        let patterns = spec
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .map(ChannelPattern::parse)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self(patterns))
+    }
+
+    /// All channels `full_name` routes into, across every matching pattern
+    pub fn channels_for(&self, full_name: &str) -> Vec<String> {
+        let mut channels: Vec<String> = self
+            .0
+            .iter()
+            .flat_map(|p| p.channels_for(full_name).to_vec())
+            .collect();
+        channels.sort();
+        channels.dedup();
+        channels
+    }
+}
+
+/// What happened to a tracked issue/PR since it was last seen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemAction {
+    /// First time this issue/PR has been seen
+    Opened,
+    /// Labels or other metadata changed since last seen
+    Labeled,
+    /// Closed without merging
+    Closed,
+    /// Pull request merged
+    Merged,
+}
+
+impl ItemAction {
+    fn describe(&self) -> &'static str {
+        match self {
+            ItemAction::Opened => "opened",
+            ItemAction::Labeled => "labeled",
+            ItemAction::Closed => "closed",
+            ItemAction::Merged => "merged",
+        }
+    }
+}
+
+const STATE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SeenItem {
+    guid: String,
+    state: String,
+    merged: bool,
+    updated_at: DateTime<Utc>,
+}
+
+/// Versioned, on-disk record of issue/PR actions already emitted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackState {
+    version: u32,
+    seen: HashMap<String, SeenItem>,
+}
+
+impl Default for TrackState {
+    fn default() -> Self {
+        Self {
+            version: STATE_VERSION,
+            seen: HashMap::new(),
+        }
+    }
+}
+
+impl TrackState {
+    /// Load state from `path`, starting fresh if it doesn't exist yet
+    pub fn load(path: &Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Persist state to `path`, writing to a temp file and renaming into place
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp_path = path.with_extension("tmp");
+        std::fs::write(&tmp_path, serde_json::to_string_pretty(self)?)?;
+        std::fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    /// Diff `issues` against previously-seen state, updating `self` in place
+    /// and returning each issue paired with the action that changed it
+    pub fn diff(&mut self, issues: &[Issue]) -> Vec<(Issue, ItemAction)> {
+        let mut changes = Vec::new();
+
+        for issue in issues {
+            let key = format!("{}#{}", issue.repo_full_name, issue.number);
+            let previous = self.seen.get(&key);
+
+            let action = match previous {
+                None => Some(ItemAction::Opened),
+                Some(prev) if issue.merged && !prev.merged => Some(ItemAction::Merged),
+                Some(prev) if issue.state == "closed" && prev.state != "closed" => {
+                    Some(ItemAction::Closed)
+                }
+                Some(prev) if issue.updated_at > prev.updated_at => Some(ItemAction::Labeled),
+                _ => None,
+            };
+
+            let Some(action) = action else {
+                continue;
+            };
+
+            let guid = previous.map(|p| p.guid.clone()).unwrap_or_else(|| key.clone());
+            changes.push((issue.clone(), action));
+            self.seen.insert(
+                key,
+                SeenItem {
+                    guid,
+                    state: issue.state.clone(),
+                    merged: issue.merged,
+                    updated_at: issue.updated_at,
+                },
+            );
+        }
+
+        changes
+    }
+}
+
+/// A single RSS 2.0 item describing an issue/PR action
+#[derive(Debug, Clone)]
+pub struct RssItem {
+    pub title: String,
+    pub link: String,
+    pub description: String,
+    pub pub_date: DateTime<Utc>,
+    pub guid: String,
+}
+
+/// A single RSS 2.0 channel, one per tracked output channel
+#[derive(Debug, Clone)]
+pub struct RssChannel {
+    pub title: String,
+    pub link: String,
+    pub description: String,
+    pub items: Vec<RssItem>,
+}
+
+impl RssChannel {
+    /// Render this channel as an RSS 2.0 XML document
+    pub fn to_xml(&self) -> String {
+        let mut items = String::new();
+        for item in &self.items {
+            items.push_str(&format!(
+                "    <item>\n      <title>{}</title>\n      <link>{}</link>\n      <description>{}</description>\n      <pubDate>{}</pubDate>\n      <guid isPermaLink=\"false\">{}</guid>\n    </item>\n",
+                escape_xml(&item.title),
+                escape_xml(&item.link),
+                escape_xml(&item.description),
+                item.pub_date.to_rfc2822(),
+                escape_xml(&item.guid),
+            ));
+        }
+
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rss version=\"2.0\">\n  <channel>\n    <title>{}</title>\n    <link>{}</link>\n    <description>{}</description>\n{}  </channel>\n</rss>\n",
+            escape_xml(&self.title),
+            escape_xml(&self.link),
+            escape_xml(&self.description),
+            items,
+        )
+    }
+
+    /// Write this channel's RSS XML to `path`, atomically via a temp file
+    pub fn write_atomically(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp_path = path.with_extension("tmp");
+        std::fs::write(&tmp_path, self.to_xml())?;
+        std::fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn build_item(issue: &Issue, action: ItemAction) -> RssItem {
+    let kind = if issue.is_pull_request {
+        "Pull request"
+    } else {
+        "Issue"
+    };
+
+    RssItem {
+        title: format!(
+            "[{}] #{} {} ({})",
+            issue.repo_full_name,
+            issue.number,
+            issue.title,
+            action.describe()
+        ),
+        link: issue.url.clone(),
+        description: format!("{} was {} in {}", kind, action.describe(), issue.repo_full_name),
+        pub_date: issue.updated_at,
+        guid: format!("{}#{}", issue.repo_full_name, issue.number),
+    }
+}
+
+/// Route a batch of (issue, action) changes into one [`RssChannel`] per
+/// configured output channel that the issue's repo matches
+pub fn build_channels(
+    changes: &[(Issue, ItemAction)],
+    patterns: &ChannelPatterns,
+) -> HashMap<String, RssChannel> {
+    let mut channels: HashMap<String, RssChannel> = HashMap::new();
+
+    for (issue, action) in changes {
+        for channel_name in patterns.channels_for(&issue.repo_full_name) {
+            let channel = channels.entry(channel_name.clone()).or_insert_with(|| RssChannel {
+                title: format!("raibid: {}", channel_name),
+                link: String::new(),
+                description: format!("Tracked issue/PR activity for channel `{}`", channel_name),
+                items: Vec::new(),
+            });
+            channel.items.push(build_item(issue, *action));
+        }
+    }
+
+    channels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_issue(repo: &str, number: u64, state: &str, merged: bool) -> Issue {
+        Issue {
+            repo_full_name: repo.to_string(),
+            number,
+            title: format!("Issue {}", number),
+            url: format!("https://github.com/{}/issues/{}", repo, number),
+            labels: vec!["bug".to_string()],
+            is_pull_request: false,
+            state: state.to_string(),
+            merged,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_channel_pattern_full_length_match_only() {
+        let patterns = ChannelPatterns::parse("^infra-.*$:infra-feed").unwrap();
+        assert_eq!(patterns.channels_for("infra-prod"), vec!["infra-feed"]);
+        assert!(patterns.channels_for("not-infra-prod").is_empty());
+    }
+
+    #[test]
+    fn test_channel_pattern_multiple_channels_and_patterns() {
+        let patterns =
+            ChannelPatterns::parse("^org/infra-.*$:infra-feed ops-feed\n^org/docs-.*$:docs-feed")
+                .unwrap();
+        assert_eq!(
+            patterns.channels_for("org/infra-api"),
+            vec!["infra-feed", "ops-feed"]
+        );
+        assert_eq!(patterns.channels_for("org/docs-site"), vec!["docs-feed"]);
+        assert!(patterns.channels_for("org/other").is_empty());
+    }
+
+    #[test]
+    fn test_channel_pattern_missing_colon_errors() {
+        assert!(ChannelPatterns::parse("no-colon-here").is_err());
+    }
+
+    #[test]
+    fn test_diff_marks_new_issue_as_opened() {
+        let mut state = TrackState::default();
+        let changes = state.diff(&[test_issue("org/repo", 1, "open", false)]);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].1, ItemAction::Opened);
+    }
+
+    #[test]
+    fn test_diff_is_quiet_on_rerun_with_no_changes() {
+        // Reuse one fixture rather than calling `test_issue` twice: it stamps
+        // `updated_at` with `Utc::now()`, so two independent calls are never
+        // equal and would spuriously look `Labeled` on the second `diff`.
+        let issue = test_issue("org/repo", 1, "open", false);
+        let mut state = TrackState::default();
+        state.diff(&[issue.clone()]);
+
+        let changes = state.diff(&[issue]);
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn test_diff_detects_merge() {
+        let mut state = TrackState::default();
+        state.diff(&[test_issue("org/repo", 1, "open", false)]);
+
+        let changes = state.diff(&[test_issue("org/repo", 1, "closed", true)]);
+        assert_eq!(changes[0].1, ItemAction::Merged);
+    }
+
+    #[test]
+    fn test_diff_detects_close_without_merge() {
+        let mut state = TrackState::default();
+        state.diff(&[test_issue("org/repo", 1, "open", false)]);
+
+        let changes = state.diff(&[test_issue("org/repo", 1, "closed", false)]);
+        assert_eq!(changes[0].1, ItemAction::Closed);
+    }
+
+    #[test]
+    fn test_diff_assigns_stable_guid_across_reruns() {
+        let mut state = TrackState::default();
+        state.diff(&[test_issue("org/repo", 1, "open", false)]);
+        let first_guid = state.seen.get("org/repo#1").unwrap().guid.clone();
+
+        state.diff(&[test_issue("org/repo", 1, "closed", true)]);
+        let second_guid = state.seen.get("org/repo#1").unwrap().guid.clone();
+
+        assert_eq!(first_guid, second_guid);
+    }
+
+    #[test]
+    fn test_state_save_load_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+
+        let mut state = TrackState::default();
+        state.diff(&[test_issue("org/repo", 1, "open", false)]);
+        state.save(&path).unwrap();
+
+        let loaded = TrackState::load(&path).unwrap();
+        assert_eq!(loaded.version, STATE_VERSION);
+        assert!(loaded.seen.contains_key("org/repo#1"));
+    }
+
+    #[test]
+    fn test_state_load_missing_file_starts_fresh() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing.json");
+        let state = TrackState::load(&path).unwrap();
+        assert!(state.seen.is_empty());
+    }
+
+    #[test]
+    fn test_build_channels_routes_by_pattern() {
+        let patterns = ChannelPatterns::parse("^org/repo$:infra-feed").unwrap();
+        let changes = vec![(test_issue("org/repo", 1, "open", false), ItemAction::Opened)];
+
+        let channels = build_channels(&changes, &patterns);
+        assert_eq!(channels.len(), 1);
+        assert_eq!(channels["infra-feed"].items.len(), 1);
+    }
+
+    #[test]
+    fn test_rss_channel_to_xml_escapes_and_includes_item() {
+        let channel = RssChannel {
+            title: "raibid: infra-feed".to_string(),
+            link: String::new(),
+            description: "Tracked issue/PR activity".to_string(),
+            items: vec![RssItem {
+                title: "<bug> & things".to_string(),
+                link: "https://example.com/issues/1".to_string(),
+                description: "Issue was opened".to_string(),
+                pub_date: Utc::now(),
+                guid: "org/repo#1".to_string(),
+            }],
+        };
+
+        let xml = channel.to_xml();
+        assert!(xml.contains("&lt;bug&gt; &amp; things"));
+        assert!(xml.contains("<guid isPermaLink=\"false\">org/repo#1</guid>"));
+    }
+
+    #[test]
+    fn test_rss_channel_write_atomically() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("feed.xml");
+        let channel = RssChannel {
+            title: "raibid: infra-feed".to_string(),
+            link: String::new(),
+            description: String::new(),
+            items: vec![],
+        };
+
+        channel.write_atomically(&path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("<?xml"));
+        assert!(!dir.path().join("feed.tmp").exists());
+    }
+}
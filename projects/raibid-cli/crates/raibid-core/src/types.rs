@@ -2,7 +2,9 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::time::Duration;
 
 /// Repository metadata from GitHub
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,6 +53,9 @@ pub struct Repository {
 
     /// Topics/tags
     pub topics: Vec<String>,
+
+    /// Name of the configured forge entry this repository was fetched from
+    pub forge: String,
 }
 
 /// Repository synchronization status
@@ -73,6 +78,9 @@ pub enum SyncStatus {
 
     /// Already up to date
     UpToDate,
+
+    /// Local branch and upstream have both moved and can't fast-forward
+    Diverged,
 }
 
 impl SyncStatus {
@@ -84,6 +92,7 @@ impl SyncStatus {
                 | SyncStatus::Failed
                 | SyncStatus::Skipped
                 | SyncStatus::UpToDate
+                | SyncStatus::Diverged
         )
     }
 
@@ -155,6 +164,12 @@ pub struct FilterCriteria {
     /// Exclude patterns (glob)
     pub exclude: Vec<String>,
 
+    /// Include patterns (regex, matched against `name` or `full_name`)
+    pub include_regex: Vec<String>,
+
+    /// Exclude patterns (regex, matched against `name` or `full_name`)
+    pub exclude_regex: Vec<String>,
+
     /// Exclude archived repositories
     pub exclude_archived: bool,
 
@@ -169,6 +184,46 @@ pub struct FilterCriteria {
 
     /// Updated after date
     pub updated_after: Option<DateTime<Utc>>,
+
+    /// Fuzzy-match repository names against this query (fzf-style subsequence match)
+    pub fuzzy: Option<String>,
+
+    /// Keep repositories tagged with at least one of these topics (case-insensitive)
+    pub topics_any: Vec<String>,
+
+    /// Keep repositories tagged with all of these topics (case-insensitive)
+    pub topics_all: Vec<String>,
+}
+
+/// A single open issue or pull request, normalized across forges
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Issue {
+    /// Repository this issue/PR belongs to (`org/repo`)
+    pub repo_full_name: String,
+
+    /// Issue or PR number
+    pub number: u64,
+
+    /// Title
+    pub title: String,
+
+    /// Link to the issue/PR on the forge
+    pub url: String,
+
+    /// Labels currently applied
+    pub labels: Vec<String>,
+
+    /// True if this is a pull request rather than a plain issue
+    pub is_pull_request: bool,
+
+    /// `open` or `closed`
+    pub state: String,
+
+    /// True if a pull request has been merged
+    pub merged: bool,
+
+    /// Last update timestamp
+    pub updated_at: DateTime<Utc>,
 }
 
 /// Sync operation options
@@ -194,6 +249,18 @@ pub struct SyncOptions {
 
     /// Filter criteria
     pub filter: Option<FilterCriteria>,
+
+    /// Explicit SSH private key path, tried after the SSH agent
+    pub ssh_key_path: Option<PathBuf>,
+
+    /// Passphrase for an encrypted SSH private key
+    pub ssh_key_passphrase: Option<String>,
+
+    /// HTTPS tokens for clone/pull, keyed by `Repository::forge`
+    pub forge_tokens: HashMap<String, String>,
+
+    /// Per-repository timeout for the clone/pull operation, if any
+    pub timeout: Option<Duration>,
 }
 
 impl Default for SyncOptions {
@@ -206,6 +273,10 @@ impl Default for SyncOptions {
             use_ssh: true,
             repositories: Vec::new(),
             filter: None,
+            ssh_key_path: None,
+            ssh_key_passphrase: None,
+            forge_tokens: HashMap::new(),
+            timeout: Some(Duration::from_secs(300)),
         }
     }
 }
@@ -30,6 +30,14 @@ enum Commands {
         /// Filter repositories by pattern
         #[arg(short = 'f', long)]
         filter: Option<String>,
+
+        /// Force a fresh fetch, bypassing the on-disk cache
+        #[arg(long)]
+        refresh: bool,
+
+        /// Serve the on-disk cache only; never touch the network
+        #[arg(long, conflicts_with = "refresh")]
+        offline: bool,
     },
 
     /// Clone repositories from the organization
@@ -42,6 +50,14 @@ enum Commands {
         #[arg(short = 'f', long)]
         filter: Option<String>,
 
+        /// Force a fresh fetch, bypassing the on-disk cache
+        #[arg(long)]
+        refresh: bool,
+
+        /// Serve the on-disk cache only; never touch the network
+        #[arg(long, conflicts_with = "refresh")]
+        offline: bool,
+
         /// Specific repositories to clone
         repositories: Vec<String>,
     },
@@ -68,10 +84,41 @@ enum Commands {
         #[arg(long)]
         force: bool,
 
+        /// Force a fresh fetch, bypassing the on-disk cache
+        #[arg(long)]
+        refresh: bool,
+
+        /// Serve the on-disk cache only; never touch the network
+        #[arg(long, conflicts_with = "refresh")]
+        offline: bool,
+
         /// Specific repositories to sync
         repositories: Vec<String>,
     },
 
+    /// Poll org issues/PRs by label and emit RSS feeds per channel
+    Track {
+        /// Label to filter issues/PRs by
+        #[arg(short, long)]
+        label: String,
+
+        /// Filter repositories by pattern
+        #[arg(short = 'f', long)]
+        filter: Option<String>,
+
+        /// Channel pattern file (one `regex:channel-a channel-b` entry per line)
+        #[arg(short, long)]
+        channels: String,
+
+        /// Directory to write RSS feeds into (one `<channel>.xml` per channel)
+        #[arg(short, long, default_value = "feeds")]
+        output_dir: String,
+
+        /// Path to the state file recording already-seen issue/PR actions
+        #[arg(short, long, default_value = "track-state.json")]
+        state: String,
+    },
+
     /// Launch interactive TUI
     Tui,
 
@@ -114,16 +161,25 @@ async fn main() -> Result<()> {
     let _config = Config::load_or_default()?;
 
     match cli.command {
-        Commands::List { format, filter } => {
+        Commands::List {
+            format,
+            filter,
+            refresh,
+            offline,
+        } => {
             println!("List command not yet implemented");
             println!("Format: {}", format);
             if let Some(f) = filter {
                 println!("Filter: {}", f);
             }
+            println!("Refresh: {}", refresh);
+            println!("Offline: {}", offline);
         }
         Commands::Clone {
             all,
             filter,
+            refresh,
+            offline,
             repositories,
         } => {
             println!("Clone command not yet implemented");
@@ -131,6 +187,8 @@ async fn main() -> Result<()> {
             if let Some(f) = filter {
                 println!("Filter: {}", f);
             }
+            println!("Refresh: {}", refresh);
+            println!("Offline: {}", offline);
             if !repositories.is_empty() {
                 println!("Repositories: {:?}", repositories);
             }
@@ -141,6 +199,8 @@ async fn main() -> Result<()> {
             concurrency,
             dry_run,
             force,
+            refresh,
+            offline,
             repositories,
         } => {
             println!("Sync command not yet implemented");
@@ -153,10 +213,28 @@ async fn main() -> Result<()> {
             }
             println!("Dry run: {}", dry_run);
             println!("Force: {}", force);
+            println!("Refresh: {}", refresh);
+            println!("Offline: {}", offline);
             if !repositories.is_empty() {
                 println!("Repositories: {:?}", repositories);
             }
         }
+        Commands::Track {
+            label,
+            filter,
+            channels,
+            output_dir,
+            state,
+        } => {
+            println!("Track command not yet implemented");
+            println!("Label: {}", label);
+            if let Some(f) = filter {
+                println!("Filter: {}", f);
+            }
+            println!("Channels file: {}", channels);
+            println!("Output dir: {}", output_dir);
+            println!("State file: {}", state);
+        }
         Commands::Tui => {
             println!("TUI not yet implemented");
             println!("Launch with: raibid tui");